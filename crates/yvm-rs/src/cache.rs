@@ -0,0 +1,231 @@
+//! On-disk registry of ylem versions installed locally, backed by a `versions.cache` file
+//! under the OS data directory.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::YlemVmError,
+    install::{checksums_match, hash_file},
+    releases::hex_string,
+};
+
+const CACHE_FILE_NAME: &str = "versions.cache";
+
+/// A single ylem build recorded as installed, pairing its version with where its binary
+/// lives and the checksum it was verified against at install time.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstalledVersion {
+    pub version: Version,
+    pub path: PathBuf,
+    #[serde(with = "hex_string")]
+    pub sha256: Vec<u8>,
+}
+
+/// The on-disk `versions.cache` file contents: every ylem version installed locally.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionCache {
+    versions: Vec<InstalledVersion>,
+}
+
+impl VersionCache {
+    /// Loads the cache from disk, returning an empty cache if it doesn't exist yet.
+    pub fn load() -> Result<Self, YlemVmError> {
+        let path = cache_path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    /// Persists the cache to disk, creating its parent directory if needed.
+    pub fn save(&self) -> Result<(), YlemVmError> {
+        let path = cache_path()?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Returns every installed version, newest first.
+    pub fn installed_versions(&self) -> Vec<Version> {
+        let mut versions = self
+            .versions
+            .iter()
+            .map(|installed| installed.version.clone())
+            .collect::<Vec<_>>();
+        versions.sort_unstable_by(|a, b| b.cmp(a));
+        versions
+    }
+
+    /// Returns whether `version` is recorded as installed.
+    pub fn is_installed(&self, version: &Version) -> bool {
+        self.find(version).is_some()
+    }
+
+    /// Returns the recorded install path for `version`, if installed.
+    pub fn path_of(&self, version: &Version) -> Option<&Path> {
+        self.find(version).map(|installed| installed.path.as_path())
+    }
+
+    /// Returns the checksum `version` was verified against at install time, so it can be
+    /// re-checked later to detect tampering with the installed binary.
+    pub fn checksum_of(&self, version: &Version) -> Option<&[u8]> {
+        self.find(version)
+            .map(|installed| installed.sha256.as_slice())
+    }
+
+    /// Recomputes the SHA-256 of `version`'s installed binary and compares it against the
+    /// checksum recorded for it at install time, detecting tampering with the file on disk.
+    pub fn verify(&self, version: &Version) -> Result<bool, YlemVmError> {
+        let installed = self
+            .find(version)
+            .ok_or_else(|| YlemVmError::UnknownVersion(version.clone()))?;
+
+        let got = hash_file(&installed.path)?;
+        Ok(checksums_match(&installed.sha256, &got))
+    }
+
+    /// Records `version` as installed at `path` with the given verified `sha256`, replacing
+    /// any existing entry for the same version.
+    pub fn register(&mut self, version: Version, path: PathBuf, sha256: Vec<u8>) {
+        self.remove(&version);
+        self.versions.push(InstalledVersion {
+            version,
+            path,
+            sha256,
+        });
+    }
+
+    /// Removes `version` from the cache, if present.
+    pub fn remove(&mut self, version: &Version) {
+        self.versions
+            .retain(|installed| &installed.version != version);
+    }
+
+    fn find(&self, version: &Version) -> Option<&InstalledVersion> {
+        self.versions
+            .iter()
+            .find(|installed| &installed.version == version)
+    }
+}
+
+/// Path to the `versions.cache` file under the OS data directory.
+fn cache_path() -> Result<PathBuf, YlemVmError> {
+    let mut dir = dirs::data_dir().ok_or_else(|| {
+        YlemVmError::IoError(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "couldn't determine the OS data directory",
+        ))
+    })?;
+    dir.push("yvm");
+    dir.push(CACHE_FILE_NAME);
+    Ok(dir)
+}
+
+/// Returns every ylem version recorded as installed, newest first.
+pub fn installed_versions() -> Result<Vec<Version>, YlemVmError> {
+    Ok(VersionCache::load()?.installed_versions())
+}
+
+/// Returns whether `version` is recorded as installed.
+pub fn is_installed(version: &Version) -> Result<bool, YlemVmError> {
+    Ok(VersionCache::load()?.is_installed(version))
+}
+
+/// Records `version` as installed at `path` with the given verified `sha256`, persisting
+/// the updated cache to disk.
+pub fn register(version: Version, path: PathBuf, sha256: Vec<u8>) -> Result<(), YlemVmError> {
+    let mut cache = VersionCache::load()?;
+    cache.register(version, path, sha256);
+    cache.save()
+}
+
+/// Removes `version` from the cache, persisting the updated cache to disk.
+pub fn remove(version: &Version) -> Result<(), YlemVmError> {
+    let mut cache = VersionCache::load()?;
+    cache.remove(version);
+    cache.save()
+}
+
+/// Recomputes the SHA-256 of `version`'s installed binary and compares it against the
+/// checksum recorded for it at install time, detecting tampering with the file on disk.
+pub fn verify(version: &Version) -> Result<bool, YlemVmError> {
+    VersionCache::load()?.verify(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_then_is_installed_round_trips() {
+        let mut cache = VersionCache::default();
+        let version = Version::parse("0.8.7").unwrap();
+        assert!(!cache.is_installed(&version));
+
+        cache.register(
+            version.clone(),
+            PathBuf::from("/opt/ylem-0.8.7"),
+            vec![0xab, 0xcd],
+        );
+
+        assert!(cache.is_installed(&version));
+        assert_eq!(cache.path_of(&version), Some(Path::new("/opt/ylem-0.8.7")));
+        assert_eq!(cache.checksum_of(&version), Some([0xab, 0xcd].as_slice()));
+    }
+
+    #[test]
+    fn re_registering_replaces_the_existing_entry() {
+        let mut cache = VersionCache::default();
+        let version = Version::parse("0.8.7").unwrap();
+
+        cache.register(version.clone(), PathBuf::from("/opt/old"), vec![0x01]);
+        cache.register(version.clone(), PathBuf::from("/opt/new"), vec![0x02]);
+
+        assert_eq!(cache.installed_versions(), vec![version.clone()]);
+        assert_eq!(cache.path_of(&version), Some(Path::new("/opt/new")));
+    }
+
+    #[test]
+    fn remove_drops_the_entry() {
+        let mut cache = VersionCache::default();
+        let version = Version::parse("0.8.7").unwrap();
+
+        cache.register(
+            version.clone(),
+            PathBuf::from("/opt/ylem-0.8.7"),
+            vec![0xab],
+        );
+        cache.remove(&version);
+
+        assert!(!cache.is_installed(&version));
+        assert!(cache.installed_versions().is_empty());
+    }
+
+    #[test]
+    fn installed_versions_are_newest_first() {
+        let mut cache = VersionCache::default();
+        for v in ["0.8.5", "0.8.7", "0.8.6"] {
+            cache.register(Version::parse(v).unwrap(), PathBuf::new(), vec![]);
+        }
+
+        assert_eq!(
+            cache.installed_versions(),
+            vec![
+                Version::parse("0.8.7").unwrap(),
+                Version::parse("0.8.6").unwrap(),
+                Version::parse("0.8.5").unwrap(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,28 @@
+use semver::Version;
+
+use crate::platform::Platform;
+
+/// Errors covering ylem release discovery, resolution and installation.
+#[derive(Debug, thiserror::Error)]
+pub enum YlemVmError {
+    #[error("unsupported platform {0}")]
+    UnsupportedPlatform(Platform),
+
+    #[error("no known release for version {0}")]
+    UnknownVersion(Version),
+
+    #[error("checksum mismatch: expected {expected}, got {got}", expected = hex::encode(expected), got = hex::encode(got))]
+    ChecksumMismatch { expected: Vec<u8>, got: Vec<u8> },
+
+    #[error(transparent)]
+    UrlParseError(#[from] url::ParseError),
+
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+}
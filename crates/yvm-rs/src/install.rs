@@ -0,0 +1,194 @@
+//! Downloads a ylem binary and checks it against the SHA-256 pinned in [`Releases`].
+
+use std::{
+    ffi::OsString,
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use semver::Version;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    error::YlemVmError,
+    platform::Platform,
+    releases::{artifact_url, Releases},
+};
+
+/// Downloads the ylem binary for `version` on `platform` and writes it to `dest`, hashing
+/// the streamed bytes as they arrive and checking the finished hash against `releases`'
+/// pinned SHA-256.
+///
+/// The download is streamed to a temporary file next to `dest` and only renamed into place
+/// once the checksum is confirmed, so a failed or mismatched download never touches
+/// whatever was already installed at `dest`. On Unix, the executable bit is set on success.
+#[cfg(feature = "online")]
+pub async fn install(
+    releases: &Releases,
+    platform: Platform,
+    version: &Version,
+    dest: &Path,
+) -> Result<(), YlemVmError> {
+    use futures_util::StreamExt;
+
+    let (url, expected) = resolve(releases, platform, version)?;
+    let tmp = temp_path(dest);
+
+    let mut stream = reqwest::get(url).await?.error_for_status()?.bytes_stream();
+    let mut hasher = Sha256::new();
+    let mut file = File::create(&tmp)?;
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        hasher.update(&chunk);
+        file.write_all(&chunk)?;
+    }
+    drop(file);
+
+    finish(hasher, &expected, &tmp, dest)
+}
+
+/// Blocking version of [`install`].
+#[cfg(feature = "blocking")]
+pub fn blocking_install(
+    releases: &Releases,
+    platform: Platform,
+    version: &Version,
+    dest: &Path,
+) -> Result<(), YlemVmError> {
+    use std::io::Read;
+
+    let (url, expected) = resolve(releases, platform, version)?;
+    let tmp = temp_path(dest);
+
+    let mut response = reqwest::blocking::get(url)?.error_for_status()?;
+    let mut hasher = Sha256::new();
+    let mut file = File::create(&tmp)?;
+    let mut buf = [0u8; 8192];
+
+    loop {
+        let read = response.read(&mut buf)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buf[..read]);
+        file.write_all(&buf[..read])?;
+    }
+    drop(file);
+
+    finish(hasher, &expected, &tmp, dest)
+}
+
+/// Looks up the artifact URL and pinned checksum for `version`, ahead of streaming it.
+fn resolve(
+    releases: &Releases,
+    platform: Platform,
+    version: &Version,
+) -> Result<(url::Url, Vec<u8>), YlemVmError> {
+    let artifact = releases
+        .get_artifact(version)
+        .ok_or_else(|| YlemVmError::UnknownVersion(version.clone()))?;
+    let expected = releases
+        .get_checksum(version)
+        .ok_or_else(|| YlemVmError::UnknownVersion(version.clone()))?;
+
+    Ok((artifact_url(platform, version, artifact)?, expected))
+}
+
+/// Path to stream the download into before it's proven good, sitting next to `dest` so the
+/// final rename stays on the same filesystem.
+fn temp_path(dest: &Path) -> PathBuf {
+    let mut name = dest.file_name().unwrap_or_default().to_os_string();
+    name.push(OsString::from(".part"));
+    dest.with_file_name(name)
+}
+
+/// Verifies the finished hash against `expected`, removing `tmp` on mismatch and otherwise
+/// marking it executable and renaming it into `dest`.
+fn finish(hasher: Sha256, expected: &[u8], tmp: &Path, dest: &Path) -> Result<(), YlemVmError> {
+    let got = hasher.finalize().to_vec();
+
+    if !checksums_match(expected, &got) {
+        let _ = fs::remove_file(tmp);
+        return Err(YlemVmError::ChecksumMismatch {
+            expected: expected.to_vec(),
+            got,
+        });
+    }
+
+    set_executable(tmp)?;
+    fs::rename(tmp, dest)?;
+    Ok(())
+}
+
+/// Reads `path` off disk and returns its SHA-256, so an already-installed binary's checksum
+/// can be recomputed later (see [`crate::cache::VersionCache::verify`]).
+pub(crate) fn hash_file(path: &Path) -> Result<Vec<u8>, YlemVmError> {
+    use std::io::Read;
+
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buf = [0u8; 8192];
+
+    loop {
+        let read = file.read(&mut buf)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buf[..read]);
+    }
+
+    Ok(hasher.finalize().to_vec())
+}
+
+/// Constant-time comparison, so a mismatching checksum can't leak how many leading bytes
+/// of the download happened to match.
+pub(crate) fn checksums_match(expected: &[u8], got: &[u8]) -> bool {
+    if expected.len() != got.len() {
+        return false;
+    }
+
+    expected
+        .iter()
+        .zip(got.iter())
+        .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+        == 0
+}
+
+#[cfg(unix)]
+fn set_executable(dest: &Path) -> Result<(), YlemVmError> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut permissions = fs::metadata(dest)?.permissions();
+    permissions.set_mode(permissions.mode() | 0o111);
+    fs::set_permissions(dest, permissions)?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn set_executable(_dest: &Path) -> Result<(), YlemVmError> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksums_match_requires_equal_bytes() {
+        assert!(checksums_match(&[1, 2, 3], &[1, 2, 3]));
+        assert!(!checksums_match(&[1, 2, 3], &[1, 2, 4]));
+    }
+
+    #[test]
+    fn checksums_match_rejects_different_lengths() {
+        assert!(!checksums_match(&[1, 2, 3], &[1, 2]));
+    }
+
+    #[test]
+    fn temp_path_sits_next_to_dest() {
+        let dest = Path::new("/tmp/yvm/ylem-v0.8.7");
+        assert_eq!(temp_path(dest), Path::new("/tmp/yvm/ylem-v0.8.7.part"));
+    }
+}
@@ -0,0 +1,27 @@
+//! `yvm` implements the resolution, fetching and installation logic for
+//! [ylem](https://github.com/core-coin/ylem) releases.
+
+pub mod cache;
+pub mod error;
+pub mod install;
+pub mod platform;
+pub mod releases;
+
+pub use cache::{installed_versions, is_installed, register, remove, verify, VersionCache};
+pub use error::YlemVmError;
+pub use platform::{platform, Platform};
+pub use releases::{
+    all_releases, artifact_url, BuildInfo, ReleaseChannel, ReleaseMetadata, Releases,
+};
+
+#[cfg(feature = "blocking")]
+pub use install::blocking_install;
+
+#[cfg(feature = "blocking")]
+pub use releases::blocking_all_releases;
+
+#[cfg(feature = "online")]
+pub use releases::fetch_releases_online;
+
+#[cfg(feature = "online")]
+pub use install::install;
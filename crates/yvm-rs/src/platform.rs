@@ -0,0 +1,79 @@
+use std::{fmt, str::FromStr};
+
+/// Platform for which ylem binaries are built and released.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Platform {
+    LinuxAmd64,
+    LinuxAarch64,
+    MacOsAmd64,
+    MacOsAarch64,
+    WindowsAmd64,
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Platform::LinuxAmd64 => "linux-amd64",
+            Platform::LinuxAarch64 => "linux-aarch64",
+            Platform::MacOsAmd64 => "macosx-amd64",
+            Platform::MacOsAarch64 => "macosx-aarch64",
+            Platform::WindowsAmd64 => "windows-amd64",
+        };
+        f.write_str(s)
+    }
+}
+
+impl FromStr for Platform {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "linux-amd64" => Ok(Platform::LinuxAmd64),
+            "linux-aarch64" => Ok(Platform::LinuxAarch64),
+            "macosx-amd64" => Ok(Platform::MacOsAmd64),
+            "macosx-aarch64" => Ok(Platform::MacOsAarch64),
+            "windows-amd64" => Ok(Platform::WindowsAmd64),
+            s => Err(format!("unsupported platform \"{s}\"")),
+        }
+    }
+}
+
+/// Detects the platform the binary was built for.
+pub fn platform() -> Platform {
+    if cfg!(all(target_os = "linux", target_arch = "aarch64")) {
+        Platform::LinuxAarch64
+    } else if cfg!(target_os = "linux") {
+        Platform::LinuxAmd64
+    } else if cfg!(all(target_os = "macos", target_arch = "aarch64")) {
+        Platform::MacOsAarch64
+    } else if cfg!(target_os = "macos") {
+        Platform::MacOsAmd64
+    } else if cfg!(target_os = "windows") {
+        Platform::WindowsAmd64
+    } else {
+        Platform::LinuxAmd64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_every_variant_through_display_and_from_str() {
+        for platform in [
+            Platform::LinuxAmd64,
+            Platform::LinuxAarch64,
+            Platform::MacOsAmd64,
+            Platform::MacOsAarch64,
+            Platform::WindowsAmd64,
+        ] {
+            assert_eq!(platform.to_string().parse::<Platform>().unwrap(), platform);
+        }
+    }
+
+    #[test]
+    fn from_str_rejects_unknown_platforms() {
+        assert!("solaris-sparc".parse::<Platform>().is_err());
+    }
+}
@@ -1,5 +1,5 @@
 use once_cell::sync::Lazy;
-use semver::Version;
+use semver::{Version, VersionReq};
 use serde::{
     de::{self, Deserializer},
     Deserialize, Serialize,
@@ -21,6 +21,21 @@ static YLEM_AMD_RELEASES: Lazy<Releases> = Lazy::new(|| {
         .expect("Couldn't parse ylem releases")
 });
 
+static YLEM_MACOS_AMD_RELEASES: Lazy<Releases> = Lazy::new(|| {
+    serde_json::from_str(include_str!("../list/macos/amd64/list.json"))
+        .expect("Couldn't parse ylem releases")
+});
+
+static YLEM_MACOS_AARCH_RELEASES: Lazy<Releases> = Lazy::new(|| {
+    serde_json::from_str(include_str!("../list/macos/aarch64/list.json"))
+        .expect("Couldn't parse ylem releases")
+});
+
+static YLEM_WINDOWS_AMD_RELEASES: Lazy<Releases> = Lazy::new(|| {
+    serde_json::from_str(include_str!("../list/windows/amd64/list.json"))
+        .expect("Couldn't parse ylem releases")
+});
+
 /// Defines the struct that the JSON-formatted release list can be deserialized into.
 ///
 /// {
@@ -60,12 +75,90 @@ impl Releases {
         self.releases.get(version)
     }
 
+    /// Returns the structured [`ReleaseMetadata`] (channel and build commit) for `version`,
+    /// parsed out of its artifact name.
+    pub fn get_metadata(&self, version: &Version) -> Option<ReleaseMetadata> {
+        let artifact = self.get_artifact(version)?;
+        Some(ReleaseMetadata::parse(version.clone(), artifact))
+    }
+
+    /// Returns the build commit hash for `version`, if its artifact name carries one.
+    pub fn get_commit(&self, version: &Version) -> Option<String> {
+        self.get_metadata(version)?.commit
+    }
+
+    /// Returns the release channel (stable, nightly, alpha, beta) for `version`.
+    pub fn get_channel(&self, version: &Version) -> Option<ReleaseChannel> {
+        Some(self.get_metadata(version)?.channel)
+    }
+
+    /// Returns every version published on `channel`, newest first.
+    pub fn versions_in_channel(&self, channel: ReleaseChannel) -> Vec<&Version> {
+        let mut versions = self
+            .releases
+            .iter()
+            .filter(|(version, artifact)| {
+                ReleaseMetadata::parse_channel(version, artifact) == channel
+            })
+            .map(|(version, _)| version)
+            .collect::<Vec<_>>();
+        versions.sort_unstable_by(|a, b| b.cmp(a));
+        versions
+    }
+
     /// Returns a sorted list of all versions
     pub fn into_versions(self) -> Vec<Version> {
         let mut versions = self.releases.into_keys().collect::<Vec<_>>();
         versions.sort_unstable();
         versions
     }
+
+    /// Returns the newest version, if any.
+    pub fn latest(&self) -> Option<&Version> {
+        self.releases.keys().max()
+    }
+
+    /// Resolves a [`VersionReq`] (e.g. `^0.8`, `~0.8.7`, `0.8.*`) to the newest matching
+    /// [`Version`], skipping prereleases unless `req` itself references one.
+    pub fn resolve(&self, req: &VersionReq) -> Option<&Version> {
+        let mut versions = self.releases.keys().collect::<Vec<_>>();
+        versions.sort_unstable_by(|a, b| b.cmp(a));
+
+        let allow_prerelease = req.comparators.iter().any(|c| !c.pre.is_empty());
+
+        versions
+            .into_iter()
+            .find(|v| (allow_prerelease || v.pre.is_empty()) && req.matches(v))
+    }
+
+    /// Same as [`Releases::resolve`], but returns the matching artifact name.
+    pub fn resolve_artifact(&self, req: &VersionReq) -> Option<&String> {
+        let version = self.resolve(req)?;
+        self.get_artifact(version)
+    }
+
+    /// Same as [`Releases::resolve`], but returns the matching checksum.
+    pub fn resolve_checksum(&self, req: &VersionReq) -> Option<Vec<u8>> {
+        let version = self.resolve(req)?.clone();
+        self.get_checksum(&version)
+    }
+
+    /// Merges `other` into `self`, letting `other`'s entries take precedence on conflict.
+    ///
+    /// Useful for combining the statically embedded release list with one obtained from
+    /// [`fetch_releases_online`], so freshly published versions become resolvable without
+    /// dropping any build info already known locally.
+    pub fn merge(&mut self, other: Releases) {
+        self.releases.extend(other.releases);
+
+        for build in other.builds {
+            if let Some(existing) = self.builds.iter_mut().find(|b| b.version == build.version) {
+                *existing = build;
+            } else {
+                self.builds.push(build);
+            }
+        }
+    }
 }
 
 /// Build info contains the SHA256 checksum of a ylem binary.
@@ -76,8 +169,65 @@ pub struct BuildInfo {
     pub sha256: Vec<u8>,
 }
 
+/// The release track a build was published on, as encoded in its artifact name
+/// (e.g. the `-nightly` in `ylem-linux-amd64-v0.8.8-nightly.2023.1.1+commit.abcdef12`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReleaseChannel {
+    #[default]
+    Stable,
+    Nightly,
+    Alpha,
+    Beta,
+}
+
+/// Structured metadata parsed out of a release's artifact name, pairing its [`Version`]
+/// with the [`ReleaseChannel`] and build commit hash (the `+commit.<hash>` suffix), since
+/// the raw `BTreeMap<Version, String>` in [`Releases::releases`] throws both away.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReleaseMetadata {
+    pub version: Version,
+    pub channel: ReleaseChannel,
+    pub commit: Option<String>,
+}
+
+impl ReleaseMetadata {
+    /// Parses the channel and commit hash out of `artifact`, pairing them with `version`.
+    fn parse(version: Version, artifact: &str) -> Self {
+        let channel = Self::parse_channel(&version, artifact);
+        let commit = artifact
+            .split_once("+commit.")
+            .map(|(_, hash)| {
+                hash.split(|c: char| !c.is_ascii_hexdigit())
+                    .next()
+                    .unwrap_or(hash)
+            })
+            .filter(|hash| !hash.is_empty())
+            .map(str::to_string);
+
+        ReleaseMetadata {
+            version,
+            channel,
+            commit,
+        }
+    }
+
+    /// Determines the [`ReleaseChannel`] for `version`, preferring the marker embedded in
+    /// `artifact` and falling back to the version's own semver prerelease tag.
+    fn parse_channel(version: &Version, artifact: &str) -> ReleaseChannel {
+        if artifact.contains("-nightly") || version.pre.as_str().contains("nightly") {
+            ReleaseChannel::Nightly
+        } else if artifact.contains("-alpha") || version.pre.as_str().contains("alpha") {
+            ReleaseChannel::Alpha
+        } else if artifact.contains("-beta") || version.pre.as_str().contains("beta") {
+            ReleaseChannel::Beta
+        } else {
+            ReleaseChannel::Stable
+        }
+    }
+}
+
 /// Helper serde module to serialize and deserialize bytes as hex.
-mod hex_string {
+pub(crate) mod hex_string {
     use super::*;
     use serde::Serializer;
     pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
@@ -99,27 +249,30 @@ mod hex_string {
     }
 }
 
+/// Returns the statically embedded [`Releases`] for `platform`, if any.
+fn static_releases(platform: Platform) -> Option<&'static Lazy<Releases>> {
+    match platform {
+        Platform::LinuxAarch64 => Some(&YLEM_AARCH_RELEASES),
+        Platform::LinuxAmd64 => Some(&YLEM_AMD_RELEASES),
+        Platform::MacOsAmd64 => Some(&YLEM_MACOS_AMD_RELEASES),
+        Platform::MacOsAarch64 => Some(&YLEM_MACOS_AARCH_RELEASES),
+        Platform::WindowsAmd64 => Some(&YLEM_WINDOWS_AMD_RELEASES),
+    }
+}
+
 /// Blocking version for [`all_realeases`]
 #[cfg(feature = "blocking")]
 pub fn blocking_all_releases(platform: Platform) -> Result<Releases, YlemVmError> {
-    if platform == Platform::LinuxAarch64 {
-        Ok(YLEM_AARCH_RELEASES.clone())
-    } else if platform == Platform::LinuxAmd64 {
-        Ok(YLEM_AMD_RELEASES.clone())
-    } else {
-        Err(YlemVmError::UnsupportedPlatform(platform))
-    }
+    static_releases(platform)
+        .map(|releases| releases.clone())
+        .ok_or(YlemVmError::UnsupportedPlatform(platform))
 }
 
 /// Fetch all releases available for the provided platform.
 pub async fn all_releases(platform: Platform) -> Result<Releases, YlemVmError> {
-    if platform == Platform::LinuxAarch64 {
-        Ok(YLEM_AARCH_RELEASES.clone())
-    } else if platform == Platform::LinuxAmd64 {
-        Ok(YLEM_AMD_RELEASES.clone())
-    } else {
-        Err(YlemVmError::UnsupportedPlatform(platform))
-    }
+    static_releases(platform)
+        .map(|releases| releases.clone())
+        .ok_or(YlemVmError::UnsupportedPlatform(platform))
 }
 
 /// Construct the URL to the Ylem binary for the specified release version and target platform.
@@ -128,7 +281,7 @@ pub fn artifact_url(
     version: &Version,
     artifact: &str,
 ) -> Result<Url, YlemVmError> {
-    if platform == Platform::LinuxAmd64 || platform == Platform::LinuxAarch64 {
+    if static_releases(platform).is_some() {
         return Ok(Url::parse(&format!(
             "{YLEM_RELEASES_URL}/{version}/{artifact}"
         ))?);
@@ -136,3 +289,223 @@ pub fn artifact_url(
 
     Err(YlemVmError::UnsupportedPlatform(platform))
 }
+
+/// The GitHub Releases API endpoint listing all published `ylem` releases.
+#[cfg(feature = "online")]
+const YLEM_GITHUB_RELEASES_API: &str = "https://api.github.com/repos/core-coin/ylem/releases";
+
+/// A single entry returned by the GitHub Releases API, trimmed to the fields we care about.
+#[cfg(feature = "online")]
+#[derive(Debug, Deserialize)]
+struct GithubRelease {
+    tag_name: String,
+    assets: Vec<GithubAsset>,
+    #[serde(default)]
+    body: String,
+}
+
+#[cfg(feature = "online")]
+#[derive(Debug, Deserialize)]
+struct GithubAsset {
+    name: String,
+}
+
+/// Returns the asset-name fragment that identifies builds for `platform`, mirroring the
+/// naming scheme embedded in the static `list.json` files (e.g. `ylem-linux-amd64-v0.8.7`).
+#[cfg(feature = "online")]
+fn platform_asset_tag(platform: Platform) -> &'static str {
+    match platform {
+        Platform::LinuxAmd64 => "linux-amd64",
+        Platform::LinuxAarch64 => "linux-aarch64",
+        Platform::MacOsAmd64 => "macosx-amd64",
+        Platform::MacOsAarch64 => "macosx-aarch64",
+        Platform::WindowsAmd64 => "windows-amd64",
+    }
+}
+
+/// Queries the GitHub Releases API for every published `ylem` release and builds a
+/// [`Releases`] from the assets that match `platform`.
+///
+/// This requires network access and is meant to complement, not replace, the statically
+/// embedded release lists: merge the result into those (see [`Releases::merge`]) so
+/// `artifact_url` keeps working for versions discovered this way.
+#[cfg(feature = "online")]
+pub async fn fetch_releases_online(platform: Platform) -> Result<Releases, YlemVmError> {
+    let client = reqwest::Client::builder().user_agent("yvm-rs").build()?;
+
+    let gh_releases: Vec<GithubRelease> = client
+        .get(YLEM_GITHUB_RELEASES_API)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    let tag = platform_asset_tag(platform);
+    let mut releases = Releases::default();
+
+    for release in gh_releases {
+        let version_str = release.tag_name.trim_start_matches('v');
+        let Ok(version) = Version::parse(version_str) else {
+            continue;
+        };
+
+        let Some(asset) = release.assets.iter().find(|asset| asset.name.contains(tag)) else {
+            continue;
+        };
+
+        if let Some(sha256) = sha256_from_release_body(&release.body, &asset.name) {
+            releases.builds.push(BuildInfo {
+                version: version.clone(),
+                sha256,
+            });
+        }
+
+        releases.releases.insert(version, asset.name.clone());
+    }
+
+    Ok(releases)
+}
+
+/// Best-effort extraction of a `sha256  <asset-name>` style checksum line from a release's
+/// markdown body, as ylem releases sometimes publish checksums this way instead of (or in
+/// addition to) a dedicated checksums asset.
+#[cfg(feature = "online")]
+fn sha256_from_release_body(body: &str, asset_name: &str) -> Option<Vec<u8>> {
+    body.lines().find_map(|line| {
+        if !line.contains(asset_name) {
+            return None;
+        }
+
+        line.split_whitespace()
+            .find(|word| word.len() == 64 && word.bytes().all(|b| b.is_ascii_hexdigit()))
+            .and_then(|hex_str| hex::decode(hex_str).ok())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn releases(versions: &[&str]) -> Releases {
+        let mut releases = Releases::default();
+        for v in versions {
+            let version = Version::parse(v).unwrap();
+            releases
+                .releases
+                .insert(version, format!("ylem-linux-amd64-v{v}"));
+        }
+        releases
+    }
+
+    #[test]
+    fn resolve_picks_the_newest_match() {
+        let releases = releases(&["0.8.5", "0.8.6", "0.8.7", "0.9.0"]);
+        let req = VersionReq::parse("^0.8").unwrap();
+        assert_eq!(
+            releases.resolve(&req).unwrap(),
+            &Version::parse("0.8.7").unwrap()
+        );
+    }
+
+    #[test]
+    fn resolve_skips_prereleases_unless_requested() {
+        let releases = releases(&["0.8.7", "0.8.8-alpha"]);
+
+        let req = VersionReq::parse("^0.8").unwrap();
+        assert_eq!(
+            releases.resolve(&req).unwrap(),
+            &Version::parse("0.8.7").unwrap()
+        );
+
+        let req = VersionReq::parse("=0.8.8-alpha").unwrap();
+        assert_eq!(
+            releases.resolve(&req).unwrap(),
+            &Version::parse("0.8.8-alpha").unwrap()
+        );
+    }
+
+    #[test]
+    fn resolve_returns_none_when_nothing_matches() {
+        let releases = releases(&["0.8.7"]);
+        let req = VersionReq::parse("^1").unwrap();
+        assert!(releases.resolve(&req).is_none());
+    }
+
+    #[test]
+    fn latest_returns_the_max_version() {
+        let releases = releases(&["0.8.5", "0.8.7", "0.8.6"]);
+        assert_eq!(
+            releases.latest().unwrap(),
+            &Version::parse("0.8.7").unwrap()
+        );
+    }
+
+    #[test]
+    fn parses_commit_hash_out_of_the_artifact_name() {
+        let version = Version::parse("0.8.7").unwrap();
+        let metadata = ReleaseMetadata::parse(version, "ylem-macosx-amd64-v0.8.7+commit.e28d00a7");
+        assert_eq!(metadata.commit.as_deref(), Some("e28d00a7"));
+    }
+
+    #[test]
+    fn strips_trailing_extensions_from_the_commit_hash() {
+        let version = Version::parse("0.8.7").unwrap();
+        let metadata =
+            ReleaseMetadata::parse(version, "ylem-windows-amd64-v0.8.7+commit.e28d00a7.zip");
+        assert_eq!(metadata.commit.as_deref(), Some("e28d00a7"));
+    }
+
+    #[test]
+    fn no_commit_suffix_means_no_commit() {
+        let version = Version::parse("0.8.7").unwrap();
+        let metadata = ReleaseMetadata::parse(version, "ylem-linux-amd64-v0.8.7");
+        assert_eq!(metadata.commit, None);
+    }
+
+    #[test]
+    fn detects_channel_from_the_artifact_name() {
+        let version = Version::parse("0.8.8").unwrap();
+        assert_eq!(
+            ReleaseMetadata::parse_channel(&version, "ylem-linux-amd64-v0.8.8-nightly.1+commit.ab"),
+            ReleaseChannel::Nightly
+        );
+        assert_eq!(
+            ReleaseMetadata::parse_channel(&version, "ylem-linux-amd64-v0.8.8-alpha.1+commit.ab"),
+            ReleaseChannel::Alpha
+        );
+        assert_eq!(
+            ReleaseMetadata::parse_channel(&version, "ylem-linux-amd64-v0.8.8-beta.1+commit.ab"),
+            ReleaseChannel::Beta
+        );
+        assert_eq!(
+            ReleaseMetadata::parse_channel(&version, "ylem-linux-amd64-v0.8.8+commit.ab"),
+            ReleaseChannel::Stable
+        );
+    }
+
+    #[test]
+    fn artifact_url_builds_the_windows_zip_asset_url() {
+        let version = Version::parse("0.8.7").unwrap();
+        let url = artifact_url(
+            Platform::WindowsAmd64,
+            &version,
+            "ylem-windows-amd64-v0.8.7+commit.e28d00a7.zip",
+        )
+        .unwrap();
+
+        assert_eq!(
+            url.as_str(),
+            "https://github.com/core-coin/ylem/releases/download/0.8.7/ylem-windows-amd64-v0.8.7+commit.e28d00a7.zip"
+        );
+    }
+
+    #[test]
+    fn detects_channel_from_the_semver_prerelease_tag_as_a_fallback() {
+        let version = Version::parse("0.8.8-nightly.1").unwrap();
+        assert_eq!(
+            ReleaseMetadata::parse_channel(&version, "ylem-linux-amd64-v0.8.8-nightly.1"),
+            ReleaseChannel::Nightly
+        );
+    }
+}